@@ -1,6 +1,9 @@
-use std::{error, fmt};
+use std::collections::HashSet;
+use std::fmt;
 use std::fmt::{Debug, Display, Formatter};
+use std::time::{Duration, Instant};
 use polars::prelude::*;
+use thiserror::Error;
 
 pub const ID: &str = "id";
 pub const SRC: &str = "src";
@@ -15,59 +18,57 @@ pub struct GraphFrame {
 
 type Result<T> = std::result::Result<T, GraphFrameError>;
 
-#[derive(Debug)]
+#[derive(Debug, Error)]
+#[non_exhaustive]
 pub enum GraphFrameError {
-    FromPolars(PolarsError),
-    MissingColumn(MissingColumnError)
-}
+    #[error(transparent)]
+    FromPolars(#[from] PolarsError),
 
-impl Display for GraphFrameError {
+    #[error(transparent)]
+    MissingColumn(#[from] MissingColumnError),
 
-    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        match self {
-            GraphFrameError::FromPolars(error) => std::fmt::Display::fmt(error, f),
-            GraphFrameError::MissingColumn(error) => std::fmt::Display::fmt(error, f),
-        }
-    }
+    #[error("Invalid motif pattern: {0}")]
+    InvalidPattern(String),
 
-}
+    #[error("The `dot` renderer is not available on this system")]
+    RendererUnavailable,
 
-impl error::Error for GraphFrameError {
+    #[error("The `dot` renderer failed ({status}): {stderr}")]
+    RenderFailed {
+        status: String,
+        stderr: String
+    },
 
-    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
-        match *self {
-            GraphFrameError::FromPolars(ref e) => Some(e),
-            GraphFrameError::MissingColumn(_) => None,
-        }
-    }
+    #[error("The {column} column must share the vertices' {ID} dtype ({id_dtype:?}), found {dtype:?}")]
+    SchemaError {
+        column: &'static str,
+        id_dtype: DataType,
+        dtype: DataType
+    },
+
+    #[error("The edge endpoints {endpoints:?} are not present in the vertices")]
+    DanglingEdge {
+        endpoints: Vec<String>
+    },
 
+    #[error("The computation did not converge within {supersteps} supersteps ({elapsed:?} elapsed)")]
+    Timeout {
+        supersteps: usize,
+        elapsed: Duration
+    }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Error)]
+#[non_exhaustive]
 pub enum MissingColumnError {
+    #[error("The vertices vertices must contain a {ID} for the Graph to be created")]
     Id,
-    Src,
-    Dst
-}
 
-impl Display for MissingColumnError {
-
-    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        let message = |df, column|
-            format!("The vertices {} must contain a {} for the Graph to be created", df, column);
-        match self {
-            MissingColumnError::Id =>  write!(f, "{}", message("vertices", ID)),
-            MissingColumnError::Src => write!(f, "{}", message("edges", SRC)),
-            MissingColumnError::Dst => write!(f, "{}", message("edges", DST)),
-        }
-    }
-
-}
+    #[error("The vertices edges must contain a {SRC} for the Graph to be created")]
+    Src,
 
-impl From<PolarsError> for GraphFrameError {
-    fn from(err: PolarsError) -> GraphFrameError {
-        GraphFrameError::FromPolars(err)
-    }
+    #[error("The vertices edges must contain a {DST} for the Graph to be created")]
+    Dst
 }
 
 impl GraphFrame {
@@ -83,6 +84,14 @@ impl GraphFrame {
             return Err(GraphFrameError::MissingColumn(MissingColumnError::Dst));
         }
 
+        let id_dtype = vertices.column(ID)?.dtype().clone();
+        Self::check_dtype(SRC, edges.column(SRC)?.dtype(), &id_dtype)?;
+        Self::check_dtype(DST, edges.column(DST)?.dtype(), &id_dtype)?;
+
+        let mut endpoints = edges.column(SRC)?.clone();
+        endpoints.append(edges.column(DST)?)?;
+        Self::check_dangling(&vertices, &endpoints)?;
+
         Ok(
             GraphFrame {
                 vertices: vertices.lazy(),
@@ -91,6 +100,28 @@ impl GraphFrame {
         )
     }
 
+    /// A mismatched `SRC`/`DST` dtype would make the joins `find`/Pregel rely on silently
+    /// return no rows instead of failing, so it is rejected up front.
+    fn check_dtype(column: &'static str, dtype: &DataType, id_dtype: &DataType) -> Result<()> {
+        if dtype != id_dtype {
+            return Err(GraphFrameError::SchemaError { column, id_dtype: id_dtype.clone(), dtype: dtype.clone() });
+        }
+        Ok(())
+    }
+
+    fn check_dangling(vertices: &DataFrame, endpoints: &Series) -> Result<()> {
+        let present = endpoints.is_in(vertices.column(ID)?)?;
+        let missing = endpoints.filter(&!present)?.unique()?;
+        if missing.is_empty() {
+            return Ok(());
+        }
+
+        let endpoints = (0..missing.len())
+            .map(|index| missing.get(index).map(|value| plain_value(&value)))
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        Err(GraphFrameError::DanglingEdge { endpoints })
+    }
+
     pub fn from_edges(edges: DataFrame) -> Result<Self> {
         let srcs = edges.clone().lazy().select([col(SRC).alias(ID)]);
         let dsts = edges.clone().lazy().select([col(DST).alias(ID)]);
@@ -115,6 +146,379 @@ impl GraphFrame {
             .agg([count().alias("in_degree")])
     }
 
+    /// Runs a GraphFrames-style motif search over the graph. `pattern` is a `;`-separated list
+    /// of edge triples such as `"(a)-[e]->(b); (b)-[e2]->(c)"`. Each named element becomes a
+    /// struct column of its attributes in the returned frame, with one row per match. A named
+    /// edge's struct holds only its columns other than `src`/`dst`, so an edge frame with no
+    /// other columns yields no column for the edge. Elements
+    /// written with an empty name (`()`, `[]`) are anonymous: they are not joined on and do not
+    /// appear in the output. A triple prefixed with `!` is negated and is realized as an
+    /// anti-join against the bindings accumulated so far. Every triple (after the first) must
+    /// share a bound vertex with the triples before it — shared vertices enforce connectivity,
+    /// and a disconnected triple is rejected as an `InvalidPattern` rather than cross-joined.
+    pub fn find(&self, pattern: &str) -> Result<LazyFrame> {
+        let triples = Self::parse_pattern(pattern)?;
+
+        let mut frame: Option<LazyFrame> = None;
+        let mut bound: HashSet<String> = HashSet::new();
+
+        for triple in triples.iter().filter(|triple| !triple.negated) {
+            let edge_lf = self.edge_binding(triple)?;
+            frame = Some(match frame {
+                None => edge_lf,
+                Some(acc) => Self::join_on_shared(acc, edge_lf, &bound, &triple.src, &triple.dst)?,
+            });
+            if !triple.src.is_empty() {
+                bound.insert(triple.src.clone());
+            }
+            if !triple.dst.is_empty() {
+                bound.insert(triple.dst.clone());
+            }
+        }
+
+        let mut frame = frame
+            .ok_or_else(|| GraphFrameError::InvalidPattern(pattern.to_string()))?;
+
+        for triple in triples.iter().filter(|triple| triple.negated) {
+            let edge_lf = self.edge_binding(triple)?;
+            let shared = Self::shared_id_columns(&bound, &triple.src, &triple.dst);
+            if shared.is_empty() {
+                return Err(GraphFrameError::InvalidPattern(pattern.to_string()));
+            }
+            let keys: Vec<Expr> = shared.iter().map(|name| col(name)).collect();
+            frame = frame.join(edge_lf, keys.clone(), keys, JoinType::Anti);
+        }
+
+        for name in &bound {
+            let key = format!("{}.{}", name, ID);
+            frame = frame.inner_join(self.vertex_binding(name)?, [col(&key)], [col(&key)]);
+        }
+
+        Self::as_element_structs(frame)
+    }
+
+    /// Folds the `<element>.<column>` namespaces produced by the edge/vertex joins into one
+    /// struct column per named element, matching the columns `find` documents.
+    fn as_element_structs(frame: LazyFrame) -> Result<LazyFrame> {
+        let schema = frame.schema()?;
+        let mut elements: Vec<(String, Vec<String>)> = Vec::new();
+
+        for name in schema.iter_names() {
+            let Some((element, field)) = name.split_once('.') else { continue };
+            match elements.iter_mut().find(|(existing, _)| existing == element) {
+                Some((_, fields)) => fields.push(field.to_string()),
+                None => elements.push((element.to_string(), vec![field.to_string()])),
+            }
+        }
+
+        let exprs: Vec<Expr> = elements
+            .into_iter()
+            .map(|(element, fields)| {
+                let fields: Vec<Expr> = fields
+                    .iter()
+                    .map(|field| col(&format!("{}.{}", element, field)).alias(field))
+                    .collect();
+                as_struct(fields).alias(&element)
+            })
+            .collect();
+
+        Ok(frame.select(exprs))
+    }
+
+    fn parse_pattern(pattern: &str) -> Result<Vec<Triple>> {
+        pattern
+            .split(';')
+            .map(str::trim)
+            .filter(|chunk| !chunk.is_empty())
+            .map(Self::parse_triple)
+            .collect()
+    }
+
+    fn parse_triple(chunk: &str) -> Result<Triple> {
+        let invalid = || GraphFrameError::InvalidPattern(chunk.to_string());
+
+        let (negated, chunk) = match chunk.strip_prefix('!') {
+            Some(rest) => (true, rest),
+            None => (false, chunk),
+        };
+
+        let (src, rest) = chunk
+            .strip_prefix('(')
+            .and_then(|rest| rest.split_once(")-["))
+            .ok_or_else(invalid)?;
+        let (edge, rest) = rest.split_once("]->(").ok_or_else(invalid)?;
+        let dst = rest.strip_suffix(')').ok_or_else(invalid)?;
+
+        Ok(Triple { negated, src: src.to_string(), edge: edge.to_string(), dst: dst.to_string() })
+    }
+
+    /// Renames the edge frame's columns into `<name>.<column>` namespaces for the triple's
+    /// endpoints and edge, dropping anonymous elements entirely. `SRC`/`DST` always become
+    /// `<name>.id` (regardless of which side of the arrow they're on) so that the same vertex
+    /// referenced by two triples joins on a single, normalized key. A triple whose endpoints
+    /// share a name (e.g. the self-loop `(a)-[e]->(a)`) is first filtered to rows where
+    /// `SRC == DST`, then only `SRC` is kept under `<name>.id` so the rename doesn't produce
+    /// two columns with the same target name.
+    fn edge_binding(&self, triple: &Triple) -> Result<LazyFrame> {
+        let schema = self.edges.schema()?;
+        let self_loop = !triple.src.is_empty() && triple.src == triple.dst;
+
+        let mut from = Vec::new();
+        let mut to = Vec::new();
+
+        for name in schema.iter_names() {
+            let name = name.as_str();
+            if self_loop && name == DST {
+                continue;
+            }
+            let (namespace, renamed) = if name == SRC {
+                (&triple.src, ID.to_string())
+            } else if name == DST {
+                (&triple.dst, ID.to_string())
+            } else {
+                (&triple.edge, name.to_string())
+            };
+            if namespace.is_empty() {
+                continue;
+            }
+            from.push(name.to_string());
+            to.push(format!("{}.{}", namespace, renamed));
+        }
+
+        let mut edges = self.edges.clone();
+        if self_loop {
+            edges = edges.filter(col(SRC).eq(col(DST)));
+        }
+
+        let kept: Vec<Expr> = to.iter().map(|name| col(name)).collect();
+        Ok(edges.rename(&from, &to).select(kept))
+    }
+
+    /// Renames every vertex column into the `<name>.<column>` namespace so it can be joined
+    /// onto a bound element's id column.
+    fn vertex_binding(&self, name: &str) -> Result<LazyFrame> {
+        let schema = self.vertices.schema()?;
+        let (from, to): (Vec<String>, Vec<String>) = schema
+            .iter_names()
+            .map(|column| (column.to_string(), format!("{}.{}", name, column)))
+            .unzip();
+        Ok(self.vertices.clone().rename(&from, &to))
+    }
+
+    fn shared_id_columns(bound: &HashSet<String>, src: &str, dst: &str) -> Vec<String> {
+        [src, dst]
+            .into_iter()
+            .filter(|name| !name.is_empty() && bound.contains(*name))
+            .map(|name| format!("{}.{}", name, ID))
+            .collect()
+    }
+
+    /// Joins `rhs` onto the accumulated `acc` frame on whichever of `src`/`dst` is already
+    /// bound. A triple that shares no vertex with the triples before it would otherwise need a
+    /// cross join, silently producing a cartesian blow-up, so it is rejected instead.
+    fn join_on_shared(acc: LazyFrame, rhs: LazyFrame, bound: &HashSet<String>, src: &str, dst: &str) -> Result<LazyFrame> {
+        let shared = Self::shared_id_columns(bound, src, dst);
+        if shared.is_empty() {
+            return Err(GraphFrameError::InvalidPattern(format!(
+                "triple (({src})-[]->({dst})) shares no vertex with the triples before it"
+            )));
+        }
+        let keys: Vec<Expr> = shared.iter().map(|name| col(name)).collect();
+        Ok(acc.inner_join(rhs, keys.clone(), keys))
+    }
+
+}
+
+struct Triple {
+    negated: bool,
+    src: String,
+    edge: String,
+    dst: String
+}
+
+/// Controls how [`GraphFrame::to_dot`] renders a graph as GraphViz DOT.
+pub struct DotOptions {
+    pub directed: bool,
+    pub vertex_label_column: Option<String>,
+    pub edge_label_column: Option<String>,
+    pub row_limit: Option<usize>
+}
+
+impl Default for DotOptions {
+    fn default() -> Self {
+        DotOptions {
+            directed: true,
+            vertex_label_column: None,
+            edge_label_column: None,
+            row_limit: None
+        }
+    }
+}
+
+fn escape_dot(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Formats a cell value as text, without the quotes `AnyValue`'s `Display` puts around strings.
+fn plain_value(value: &AnyValue) -> String {
+    match value {
+        AnyValue::Utf8(value) => value.to_string(),
+        other => other.to_string(),
+    }
+}
+
+impl GraphFrame {
+
+    /// Serializes this graph to GraphViz DOT, with one node line per vertex `id` and one edge
+    /// line per `src -> dst` pair, labeled according to `opts`.
+    pub fn to_dot(&self, opts: &DotOptions) -> Result<String> {
+        let mut vertices = self.vertices.clone().collect()?;
+        let mut edges = self.edges.clone().collect()?;
+
+        if let Some(limit) = opts.row_limit {
+            vertices = vertices.head(Some(limit));
+            edges = edges.head(Some(limit));
+        }
+
+        let mut dot = format!("{} {{\n", if opts.directed { "digraph" } else { "graph" });
+
+        let ids = vertices.column(ID)?;
+        let vertex_labels = match &opts.vertex_label_column {
+            Some(column) => Some(vertices.column(column)?),
+            None => None,
+        };
+        for index in 0..vertices.height() {
+            let id = ids.get(index)?;
+            let label = match vertex_labels {
+                Some(labels) => labels.get(index)?,
+                None => id.clone(),
+            };
+            dot.push_str(&format!(
+                "  \"{}\" [label=\"{}\"];\n",
+                escape_dot(&plain_value(&id)),
+                escape_dot(&plain_value(&label))
+            ));
+        }
+
+        let srcs = edges.column(SRC)?;
+        let dsts = edges.column(DST)?;
+        let edge_labels = match &opts.edge_label_column {
+            Some(column) => Some(edges.column(column)?),
+            None => None,
+        };
+        let edge_op = if opts.directed { "->" } else { "--" };
+        for index in 0..edges.height() {
+            let src = srcs.get(index)?;
+            let dst = dsts.get(index)?;
+            dot.push_str(&format!(
+                "  \"{}\" {} \"{}\"",
+                escape_dot(&plain_value(&src)),
+                edge_op,
+                escape_dot(&plain_value(&dst))
+            ));
+            if let Some(labels) = edge_labels {
+                let label = labels.get(index)?;
+                dot.push_str(&format!(" [label=\"{}\"]", escape_dot(&plain_value(&label))));
+            }
+            dot.push_str(";\n");
+        }
+
+        dot.push_str("}\n");
+        Ok(dot)
+    }
+
+}
+
+#[cfg(feature = "render")]
+pub enum Format {
+    Svg,
+    Png
+}
+
+#[cfg(feature = "render")]
+impl GraphFrame {
+
+    /// Renders this graph to `path` by shelling out to the GraphViz `dot` binary. Only a
+    /// failure to spawn `dot` itself is reported as [`GraphFrameError::RendererUnavailable`];
+    /// a `dot` process that runs and exits non-zero is a [`GraphFrameError::RenderFailed`].
+    pub fn render_to(&self, path: &str, format: Format, opts: &DotOptions) -> Result<()> {
+        use std::io::Write;
+        use std::process::{Command, Stdio};
+
+        let dot = self.to_dot(opts)?;
+        let format_flag = match format {
+            Format::Svg => "svg",
+            Format::Png => "png",
+        };
+
+        let mut child = Command::new("dot")
+            .args(["-T", format_flag, "-o", path])
+            .stdin(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|_| GraphFrameError::RendererUnavailable)?;
+
+        let render_failed = |error: std::io::Error| GraphFrameError::RenderFailed {
+            status: "the process could not be driven to completion".to_string(),
+            stderr: error.to_string()
+        };
+
+        child
+            .stdin
+            .take()
+            .ok_or_else(|| render_failed(std::io::Error::from(std::io::ErrorKind::BrokenPipe)))?
+            .write_all(dot.as_bytes())
+            .map_err(render_failed)?;
+
+        let output = child.wait_with_output().map_err(render_failed)?;
+        if !output.status.success() {
+            return Err(GraphFrameError::RenderFailed {
+                status: output.status.to_string(),
+                stderr: String::from_utf8_lossy(&output.stderr).into_owned()
+            });
+        }
+        Ok(())
+    }
+
+}
+
+/// Bounds a superstep loop so a computation that never converges fails with
+/// [`GraphFrameError::Timeout`] instead of running forever. `tick` is called once per superstep
+/// by the Pregel loop in the `pregel` module.
+pub struct ConvergenceGuard {
+    max_supersteps: Option<usize>,
+    deadline: Option<Instant>,
+    started_at: Instant,
+    supersteps: usize
+}
+
+impl ConvergenceGuard {
+
+    pub fn new(max_supersteps: Option<usize>, wall_clock_limit: Option<Duration>) -> Self {
+        let started_at = Instant::now();
+        ConvergenceGuard {
+            max_supersteps,
+            deadline: wall_clock_limit.map(|limit| started_at + limit),
+            started_at,
+            supersteps: 0
+        }
+    }
+
+    /// Call once per completed superstep. Returns `Err(GraphFrameError::Timeout)` once the
+    /// superstep budget or wall-clock deadline has been exceeded.
+    pub fn tick(&mut self) -> Result<()> {
+        self.supersteps += 1;
+
+        let exceeded_supersteps = self.max_supersteps.map_or(false, |max| self.supersteps > max);
+        let exceeded_deadline = self.deadline.map_or(false, |deadline| Instant::now() >= deadline);
+
+        if exceeded_supersteps || exceeded_deadline {
+            return Err(GraphFrameError::Timeout { supersteps: self.supersteps, elapsed: self.started_at.elapsed() });
+        }
+
+        Ok(())
+    }
+
 }
 
 impl Display for GraphFrame {
@@ -131,4 +535,181 @@ impl Display for GraphFrame {
         write!(f, "Vertices: {}\nEdges: {}", vertices, edges)
     }
 
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn find_joins_two_triples_on_a_shared_vertex() {
+        let vertices = df![ID => ["a", "b", "c"]].unwrap();
+        let edges = df![
+            SRC => ["a", "b"],
+            DST => ["b", "c"],
+            "weight" => [1i64, 2i64]
+        ].unwrap();
+        let graph = GraphFrame::new(vertices, edges).unwrap();
+
+        let matches = graph
+            .find("(a)-[e]->(b); (b)-[e2]->(c)")
+            .unwrap()
+            .collect()
+            .unwrap();
+
+        assert_eq!(matches.height(), 1);
+
+        let mut columns = matches.get_column_names();
+        columns.sort_unstable();
+        assert_eq!(columns, vec!["a", "b", "c", "e", "e2"]);
+
+        let e = matches.column("e").unwrap().struct_().unwrap();
+        assert_eq!(e.field_by_name("weight").unwrap().get(0).unwrap(), AnyValue::Int64(1));
+    }
+
+    #[test]
+    fn to_dot_escapes_labels_and_renders_directed_edges() {
+        let vertices = df![
+            ID => ["a", "b"],
+            "name" => ["A\"", "B"]
+        ].unwrap();
+        let edges = df![
+            SRC => ["a"],
+            DST => ["b"],
+            "kind" => ["likes"]
+        ].unwrap();
+        let graph = GraphFrame::new(vertices, edges).unwrap();
+
+        let opts = DotOptions {
+            vertex_label_column: Some("name".to_string()),
+            edge_label_column: Some("kind".to_string()),
+            ..DotOptions::default()
+        };
+
+        let dot = graph.to_dot(&opts).unwrap();
+
+        assert!(dot.starts_with("digraph {\n"));
+        assert!(dot.contains("\"a\" [label=\"A\\\"\"];"));
+        assert!(dot.contains("\"a\" -> \"b\" [label=\"likes\"];"));
+    }
+
+    #[test]
+    fn to_dot_renders_undirected_edges_without_labels() {
+        let vertices = df![ID => ["a", "b"]].unwrap();
+        let edges = df![SRC => ["a"], DST => ["b"]].unwrap();
+        let graph = GraphFrame::new(vertices, edges).unwrap();
+
+        let opts = DotOptions { directed: false, ..DotOptions::default() };
+        let dot = graph.to_dot(&opts).unwrap();
+
+        assert!(dot.starts_with("graph {\n"));
+        assert!(dot.contains("\"a\" -- \"b\";"));
+    }
+
+    #[test]
+    fn new_rejects_a_src_dtype_that_does_not_match_id() {
+        let vertices = df![ID => ["a", "b"]].unwrap();
+        let edges = df![
+            SRC => [1i64, 2i64],
+            DST => ["a", "b"]
+        ].unwrap();
+
+        let error = GraphFrame::new(vertices, edges).unwrap_err();
+        assert!(matches!(error, GraphFrameError::SchemaError { column: SRC, .. }));
+    }
+
+    #[test]
+    fn new_lists_every_dangling_edge_endpoint() {
+        let vertices = df![ID => ["a"]].unwrap();
+        let edges = df![
+            SRC => ["a", "a"],
+            DST => ["missing1", "missing2"]
+        ].unwrap();
+
+        let error = GraphFrame::new(vertices, edges).unwrap_err();
+        match error {
+            GraphFrameError::DanglingEdge { mut endpoints } => {
+                endpoints.sort_unstable();
+                assert_eq!(endpoints, vec!["missing1".to_string(), "missing2".to_string()]);
+            }
+            other => panic!("expected DanglingEdge, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn tick_surfaces_timeout_once_the_superstep_budget_is_exhausted() {
+        let mut guard = ConvergenceGuard::new(Some(1), None);
+
+        guard.tick().unwrap();
+        let error = guard.tick().unwrap_err();
+
+        assert!(matches!(error, GraphFrameError::Timeout { supersteps: 2, .. }));
+    }
+
+    #[test]
+    fn find_excludes_matches_via_a_negated_anonymous_triple() {
+        let vertices = df![ID => ["a", "b1", "b2", "c"]].unwrap();
+        let edges = df![
+            SRC => ["a", "a", "b1"],
+            DST => ["b1", "b2", "c"]
+        ].unwrap();
+        let graph = GraphFrame::new(vertices, edges).unwrap();
+
+        let matches = graph
+            .find("(a)-[e]->(b); !(b)-[]->()")
+            .unwrap()
+            .collect()
+            .unwrap();
+
+        // `b1` has an outgoing edge and is excluded; `c` has none, so (b1)->(c) matches too.
+        assert_eq!(matches.height(), 2);
+
+        let a = matches.column("a").unwrap().struct_().unwrap().field_by_name(ID).unwrap();
+        let b = matches.column("b").unwrap().struct_().unwrap().field_by_name(ID).unwrap();
+        let (a, b) = (a.utf8().unwrap(), b.utf8().unwrap());
+        let mut pairs: Vec<(&str, &str)> = (0..matches.height())
+            .map(|row| (a.get(row).unwrap(), b.get(row).unwrap()))
+            .collect();
+        pairs.sort_unstable();
+        assert_eq!(pairs[0], ("a", "b2"));
+        assert_eq!(pairs[1], ("b1", "c"));
+    }
+
+    #[test]
+    fn find_drops_anonymous_elements_from_the_output() {
+        let vertices = df![ID => ["a", "b"]].unwrap();
+        let edges = df![SRC => ["a"], DST => ["b"]].unwrap();
+        let graph = GraphFrame::new(vertices, edges).unwrap();
+
+        let matches = graph.find("()-[]->(b)").unwrap().collect().unwrap();
+
+        assert_eq!(matches.height(), 1);
+        assert_eq!(matches.get_column_names(), vec!["b"]);
+
+        let b = matches.column("b").unwrap().struct_().unwrap();
+        assert_eq!(b.field_by_name(ID).unwrap().get(0).unwrap(), AnyValue::Utf8("b"));
+    }
+
+    #[test]
+    fn find_matches_a_self_loop_triple() {
+        let vertices = df![ID => ["a", "b"]].unwrap();
+        let edges = df![SRC => ["a", "a"], DST => ["a", "b"]].unwrap();
+        let graph = GraphFrame::new(vertices, edges).unwrap();
+
+        let matches = graph.find("(a)-[e]->(a)").unwrap().collect().unwrap();
+
+        assert_eq!(matches.height(), 1);
+        let a = matches.column("a").unwrap().struct_().unwrap();
+        assert_eq!(a.field_by_name(ID).unwrap().get(0).unwrap(), AnyValue::Utf8("a"));
+    }
+
+    #[test]
+    fn find_rejects_a_disconnected_pattern() {
+        let vertices = df![ID => ["a", "b", "c", "d"]].unwrap();
+        let edges = df![SRC => ["a", "c"], DST => ["b", "d"]].unwrap();
+        let graph = GraphFrame::new(vertices, edges).unwrap();
+
+        let error = graph.find("(a)-[e]->(b); (c)-[e2]->(d)").unwrap_err();
+        assert!(matches!(error, GraphFrameError::InvalidPattern(_)));
+    }
 }
\ No newline at end of file
@@ -0,0 +1,2 @@
+pub mod graph_frame;
+pub mod pregel;
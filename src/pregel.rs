@@ -0,0 +1,44 @@
+use polars::prelude::*;
+
+use crate::graph_frame::{ConvergenceGuard, GraphFrame, GraphFrameError, DST, ID, MSG, SRC};
+
+type Result<T> = std::result::Result<T, GraphFrameError>;
+
+/// Runs Pregel-style supersteps over `graph`: every edge sends `msg` to its destination, `agg`
+/// combines the messages landing on a vertex, and `update` folds the aggregated `msg` column
+/// into that vertex's new state. Supersteps repeat until one leaves every vertex unchanged, or
+/// `guard` runs out of superstep/wall-clock budget, in which case it surfaces `GraphFrameError::Timeout`.
+pub fn run(
+    graph: &GraphFrame,
+    msg: Expr,
+    agg: Expr,
+    update: Expr,
+    guard: &mut ConvergenceGuard
+) -> Result<DataFrame> {
+    let mut vertices = graph.vertices.clone().collect()?;
+
+    loop {
+        let messages = graph
+            .edges
+            .clone()
+            .inner_join(vertices.clone().lazy(), [col(SRC)], [col(ID)])
+            .select([col(DST).alias(ID), msg.clone().alias(MSG)])
+            .groupby([col(ID)])
+            .agg([agg.clone().alias(MSG)]);
+
+        let next = vertices
+            .clone()
+            .lazy()
+            .left_join(messages, [col(ID)], [col(ID)])
+            .with_column(update.clone())
+            .select([col("*").exclude([MSG])])
+            .collect()?;
+
+        if next.frame_equal_missing(&vertices) {
+            return Ok(next);
+        }
+
+        guard.tick()?;
+        vertices = next;
+    }
+}